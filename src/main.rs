@@ -1,13 +1,76 @@
-use std::{f32::consts::PI, ops::RangeInclusive};
+use std::{
+    f32::consts::PI,
+    fs::File,
+    io::BufReader,
+    net::SocketAddr,
+    ops::RangeInclusive,
+    path::Path,
+    time::SystemTime,
+};
 
-use bevy::{prelude::*, sprite::Anchor, utils::FloatOrd};
+use bevy::{
+    diagnostic::{Diagnostics, FrameTimeDiagnosticsPlugin},
+    prelude::*,
+    sprite::Anchor,
+    utils::FloatOrd,
+    winit::WinitSettings,
+};
+use bevy_ggrs::{GGRSPlugin, PlayerInputs, Rollback, RollbackIdProvider};
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 use bevy_rapier2d::prelude::*;
+use ggrs::{PlayerType, SessionBuilder, UdpNonBlockingSocket};
 use iyes_loopless::{
     prelude::{AppLooplessStateExt, ConditionSet, IntoConditionalSystem},
-    state::NextState,
+    state::{CurrentState, NextState},
 };
-use rand::prelude::random;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Fixed tick rate the rollback simulation (inputs, obstacle spawning, physics) runs at.
+/// GGRS resimulates whole ticks on misprediction, so this must stay deterministic.
+const ROLLBACK_FPS: usize = 60;
+
+const INPUT_FLAP: u8 = 1 << 0;
+/// Bits 1-2 carry the sender's currently selected `BirdPreset` (`BirdPreset::to_bits`),
+/// so both peers apply the same preset per handle instead of each reading its own
+/// local `SelectedBirdPreset` for handle 0.
+const INPUT_PRESET_SHIFT: u8 = 1;
+const INPUT_PRESET_MASK: u8 = 0b11 << INPUT_PRESET_SHIFT;
+
+/// Bitpacked input GGRS exchanges between peers every tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct BirdInput {
+    buttons: u8,
+}
+
+impl BirdInput {
+    fn preset(self) -> BirdPreset {
+        BirdPreset::from_bits((self.buttons & INPUT_PRESET_MASK) >> INPUT_PRESET_SHIFT)
+    }
+}
+
+struct BirdGgrsConfig;
+
+impl ggrs::Config for BirdGgrsConfig {
+    type Input = BirdInput;
+    type State = u8;
+    type Address = SocketAddr;
+}
+
+/// Seeded PRNG whose state is rolled back/restored alongside the rest of the
+/// simulation, so `spawn_obstacles` produces identical `y_mid` values on both peers.
+#[derive(Resource, Clone)]
+struct RollbackRng(StdRng);
+
+impl RollbackRng {
+    fn from_seed(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+#[derive(Component)]
+struct PlayerHandle(usize);
 
 const SCREEN_WIDTH: f32 = 1280.0;
 const SCREEN_HEIGHT: f32 = 720.0;
@@ -22,26 +85,146 @@ struct Bounds;
 struct ScoreText;
 
 #[derive(Component)]
-struct ObstacleBundle;
+struct FpsText;
+
+#[derive(Component)]
+struct PresetText;
+
+/// Whether the FPS/frame-time overlay is visible, toggled by `toggle_fps_display`.
+#[derive(Resource)]
+struct ShowFps(bool);
+
+impl Default for ShowFps {
+    fn default() -> Self {
+        Self(false)
+    }
+}
 
 #[derive(Component)]
+struct ObstacleBundle;
+
+#[derive(Component, Clone)]
 struct Bird {
     alive: bool,
 }
 
+/// Scales the flap impulse (`&FlapImpulseMultiplier`) and gravity
+/// (`GravityScale`) an individual `Bird` feels, set once at spawn from the
+/// chosen [`BirdPreset`] and left alone afterwards.
+#[derive(Component)]
+struct FlapImpulseMultiplier(f32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BirdPreset {
+    Heavy,
+    Floaty,
+    Balanced,
+}
+
+impl BirdPreset {
+    fn cycle_next(self) -> Self {
+        match self {
+            BirdPreset::Heavy => BirdPreset::Floaty,
+            BirdPreset::Floaty => BirdPreset::Balanced,
+            BirdPreset::Balanced => BirdPreset::Heavy,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            BirdPreset::Heavy => "Heavy",
+            BirdPreset::Floaty => "Floaty",
+            BirdPreset::Balanced => "Balanced",
+        }
+    }
+
+    /// Packs the preset into the 2 bits of `BirdInput` reserved for it, so each
+    /// peer's menu choice rides along with its regular input and both sides
+    /// agree on the same preset per handle once GGRS syncs the input.
+    fn to_bits(self) -> u8 {
+        match self {
+            BirdPreset::Heavy => 0,
+            BirdPreset::Floaty => 1,
+            BirdPreset::Balanced => 2,
+        }
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        match bits {
+            0 => BirdPreset::Heavy,
+            1 => BirdPreset::Floaty,
+            _ => BirdPreset::Balanced,
+        }
+    }
+
+    fn stats(self) -> BirdPresetStats {
+        match self {
+            BirdPreset::Heavy => BirdPresetStats {
+                texture: "images/player_heavy.png",
+                collider_radius: 28.0,
+                restitution: 1.2,
+                gravity_scale: 1.4,
+                flap_impulse_multiplier: 0.85,
+            },
+            BirdPreset::Floaty => BirdPresetStats {
+                texture: "images/player_floaty.png",
+                collider_radius: 22.0,
+                restitution: 2.6,
+                gravity_scale: 0.65,
+                flap_impulse_multiplier: 1.0,
+            },
+            BirdPreset::Balanced => BirdPresetStats {
+                texture: "images/player.png",
+                collider_radius: 25.0,
+                restitution: 2.0,
+                gravity_scale: 1.0,
+                flap_impulse_multiplier: 1.0,
+            },
+        }
+    }
+}
+
+struct BirdPresetStats {
+    texture: &'static str,
+    collider_radius: f32,
+    restitution: f32,
+    gravity_scale: f32,
+    flap_impulse_multiplier: f32,
+}
+
+/// Character picked on the main menu via `cycle_bird_preset`. Packed into this
+/// peer's `BirdInput` by `read_local_input` and exchanged with the other peer
+/// through GGRS, so `setup_players` can read back an agreed-upon preset per
+/// handle rather than this (purely local) resource directly.
+#[derive(Resource, Clone, Copy)]
+struct SelectedBirdPreset(BirdPreset);
+
+impl Default for SelectedBirdPreset {
+    fn default() -> Self {
+        Self(BirdPreset::Balanced)
+    }
+}
+
 #[derive(Component)]
 struct Wall;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum GameState {
+    MainMenu,
     Running,
-    Ended,
+    GameOver,
 }
 
+#[derive(Component)]
+struct MainMenuUi;
+
+#[derive(Component)]
+struct GameOverUi;
+
 #[derive(Resource)]
 struct PauseState(bool);
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 struct Score(i32);
 
 impl Default for Score {
@@ -50,6 +233,30 @@ impl Default for Score {
     }
 }
 
+const HIGH_SCORE_PATH: &str = "high_score.ron";
+
+/// Best `Score` seen across runs, loaded from and persisted to [`HIGH_SCORE_PATH`]
+/// so it survives restarts. Lives for the whole app, unlike the per-match `Score`.
+#[derive(Resource, Serialize, Deserialize, Clone, Copy, Default)]
+struct HighScore(i32);
+
+fn load_high_score() -> HighScore {
+    match File::open(HIGH_SCORE_PATH) {
+        Ok(file) => ron::de::from_reader(BufReader::new(file)).unwrap_or_default(),
+        Err(_) => HighScore::default(),
+    }
+}
+
+fn save_high_score(high_score: &HighScore) {
+    let Ok(file) = File::create(HIGH_SCORE_PATH) else {
+        warn!("failed to open {HIGH_SCORE_PATH:?} for writing high score");
+        return;
+    };
+    if let Err(err) = ron::ser::to_writer(file, high_score) {
+        warn!("failed to write high score to {HIGH_SCORE_PATH:?}: {err}");
+    }
+}
+
 #[derive(Resource)]
 struct ObstacleConfig {
     min_x_between: f32,
@@ -67,6 +274,139 @@ impl Default for ObstacleConfig {
     }
 }
 
+impl From<&GameConfig> for ObstacleConfig {
+    fn from(config: &GameConfig) -> Self {
+        Self {
+            min_x_between: config.obstacle_min_x_between,
+            y_mid_range: config.obstacle_y_mid_range_min..=config.obstacle_y_mid_range_max,
+            y_mid_offset: config.obstacle_y_mid_offset,
+        }
+    }
+}
+
+/// Floors/clamps `score_increases_difficulty` uses to tighten `ObstacleConfig`
+/// as the score climbs. Each value moves by one unit per point of score; that
+/// rate is fixed and not part of this config. See [`GameConfig`] for where
+/// these are tuned from.
+#[derive(Deserialize, Clone, Copy)]
+struct DifficultyConfig {
+    min_x_between_floor: f32,
+    y_mid_offset_floor: f32,
+    y_mid_range_clamp: f32,
+}
+
+impl Default for DifficultyConfig {
+    fn default() -> Self {
+        Self {
+            min_x_between_floor: 500.0,
+            y_mid_offset_floor: 100.0,
+            y_mid_range_clamp: 200.0,
+        }
+    }
+}
+
+/// Tuning loaded from `assets/config/game.ron` at startup (see [`load_game_config`]),
+/// so designers can retune obstacle difficulty and physics feel without recompiling.
+/// Falls back to these same `Default` values when the file is missing or malformed.
+#[derive(Resource, Deserialize, Clone)]
+#[serde(default)]
+struct GameConfig {
+    gravity: f32,
+    jump_impulse: f32,
+    obstacle_min_x_between: f32,
+    obstacle_y_mid_range_min: f32,
+    obstacle_y_mid_range_max: f32,
+    obstacle_y_mid_offset: f32,
+    difficulty: DifficultyConfig,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        let obstacle = ObstacleConfig::default();
+        Self {
+            gravity: -9.81 * 45.0,
+            jump_impulse: 50.0,
+            obstacle_min_x_between: obstacle.min_x_between,
+            obstacle_y_mid_range_min: *obstacle.y_mid_range.start(),
+            obstacle_y_mid_range_max: *obstacle.y_mid_range.end(),
+            obstacle_y_mid_offset: obstacle.y_mid_offset,
+            difficulty: DifficultyConfig::default(),
+        }
+    }
+}
+
+const GAME_CONFIG_PATH: &str = "assets/config/game.ron";
+
+/// Tracks the config file's last-modified time so `hot_reload_game_config` can
+/// tell when to re-read it without hitting disk for a full parse every frame.
+#[derive(Resource, Default)]
+struct GameConfigWatch {
+    last_modified: Option<SystemTime>,
+}
+
+fn load_game_config(path: impl AsRef<Path>) -> GameConfig {
+    let path = path.as_ref();
+    match File::open(path) {
+        Ok(file) => match ron::de::from_reader(BufReader::new(file)) {
+            Ok(config) => config,
+            Err(err) => {
+                warn!("malformed game config at {path:?}, using defaults: {err}");
+                GameConfig::default()
+            }
+        },
+        Err(_) => {
+            warn!("no game config found at {path:?}, using defaults");
+            GameConfig::default()
+        }
+    }
+}
+
+fn file_modified_time(path: impl AsRef<Path>) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+fn setup_game_config(mut commands: Commands, mut rapier_configuration: ResMut<RapierConfiguration>) {
+    let config = load_game_config(GAME_CONFIG_PATH);
+    rapier_configuration.gravity = Vec2::Y * config.gravity;
+    commands.insert_resource(GameConfigWatch {
+        last_modified: file_modified_time(GAME_CONFIG_PATH),
+    });
+    commands.insert_resource(config);
+}
+
+fn add_obstacle_config_from_game_config(mut commands: Commands, config: Res<GameConfig>) {
+    commands.insert_resource(ObstacleConfig::from(config.as_ref()));
+}
+
+/// Polls the config file's mtime and reloads it into the `GameConfig` resource
+/// on change, so designers can retune obstacle difficulty without recompiling.
+/// Every `GameConfig` field feeds the rollback sim, directly (`gravity`,
+/// `jump_impulse`) or indirectly (`obstacle_*`/`difficulty`, via the
+/// `ObstacleConfig` that `spawn_obstacles` reads each rollback tick), and
+/// neither `GameConfig` nor the file watch is rollback-registered or synced
+/// between peers. Re-reading it live would let the two sides' simulations
+/// silently diverge, so hot-reload is a no-op during a `NetplayMode::Online`
+/// match; solo play (which has no peer to diverge from) keeps reloading.
+fn hot_reload_game_config(
+    mut config: ResMut<GameConfig>,
+    mut watch: ResMut<GameConfigWatch>,
+    mut rapier_configuration: ResMut<RapierConfiguration>,
+    mode: Res<NetplayMode>,
+) {
+    if matches!(*mode, NetplayMode::Online(_)) {
+        return;
+    }
+
+    let modified = file_modified_time(GAME_CONFIG_PATH);
+    if modified.is_none() || modified == watch.last_modified {
+        return;
+    }
+
+    watch.last_modified = modified;
+    *config = load_game_config(GAME_CONFIG_PATH);
+    rapier_configuration.gravity = Vec2::Y * config.gravity;
+}
+
 fn main() {
     App::new()
         // Plugins
@@ -84,43 +424,69 @@ fn main() {
                 })
                 .set(ImagePlugin::default_nearest()),
         )
-        .add_plugin(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
+        // `with_default_system_setup(false)` stops Rapier from stepping on its own stage;
+        // the rollback schedule below steps it instead so resimulation re-runs physics too.
+        .add_plugin(
+            RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0)
+                .with_default_system_setup(false),
+        )
         .add_plugin(RapierDebugRenderPlugin::default())
         .add_plugin(WorldInspectorPlugin)
+        .add_plugin(FrameTimeDiagnosticsPlugin::default())
+        .add_ggrs_rollback()
         // Constant Resources
         .insert_resource(RapierConfiguration {
-            gravity: Vec2::Y * -9.81 * 45.0,
+            timestep_mode: TimestepMode::Fixed {
+                dt: 1.0 / ROLLBACK_FPS as f32,
+                substeps: 1,
+            },
             ..default()
         })
         .insert_resource(ClearColor(Color::rgb_u8(173, 230, 255)))
         .insert_resource(PauseState(false))
+        .insert_resource(Resimulating::default())
+        .insert_resource(load_high_score())
+        .insert_resource(ShowFps::default())
+        .insert_resource(SelectedBirdPreset::default())
+        .insert_resource(WinitSettings::game())
+        .add_event::<AudioEvent>()
+        .add_event::<ParticleBurst>()
         // Global setup
-        .add_loopless_state(GameState::Running)
+        .add_loopless_state(GameState::MainMenu)
         .add_startup_system(setup_camera)
+        .add_startup_system(setup_game_config)
+        .add_startup_system(insert_ggrs_session)
+        .add_startup_system(play_background_music)
+        .add_system(hot_reload_game_config)
+        .add_system(toggle_fps_display)
+        .add_system(adaptive_power_mode)
+        // Main Menu
+        .add_enter_system(GameState::MainMenu, setup_main_menu_ui)
+        .add_system(start_game_on_space.run_in_state(GameState::MainMenu))
+        .add_system(cycle_bird_preset.run_in_state(GameState::MainMenu))
+        .add_system(update_preset_text.run_in_state(GameState::MainMenu))
+        .add_exit_system(GameState::MainMenu, despawn_components::<MainMenuUi>)
         // Game Running
-        .add_enter_system(GameState::Running, add_resource::<ObstacleConfig>)
+        .add_enter_system(GameState::Running, add_obstacle_config_from_game_config)
         .add_enter_system(GameState::Running, add_resource::<Score>)
-        .add_enter_system(GameState::Running, setup_bird)
+        .add_enter_system(GameState::Running, setup_players)
         .add_enter_system(GameState::Running, setup_bounds)
         .add_enter_system(GameState::Running, setup_ui)
         .add_enter_system(GameState::Running, reset_camera)
         .add_system(toggle_pause)
+        .add_system(update_fps_text.run_in_state(GameState::Running))
         .add_system_set(
             ConditionSet::new()
                 .run_in_state(GameState::Running)
                 .run_if_not(is_paused)
                 .with_system(tiling_background)
-                .with_system(jump_on_space)
                 .with_system(camera_follows_bird)
-                .with_system(spawn_obstacles)
-                .with_system(despawn_offscreen_obstacles)
-                .with_system(increment_score.run_on_event::<CollisionEvent>())
-                .with_system(kill_bird_on_collision.run_on_event::<ContactForceEvent>())
                 .with_system(bounds_follow_bird)
-                .with_system(bird_rotates_with_velocity)
                 .with_system(update_score_text)
                 .with_system(score_increases_difficulty)
-                .with_system(end_on_bird_leaves_screen)
+                .with_system(play_audio)
+                .with_system(spawn_particles)
+                .with_system(update_particles)
                 .into(),
         )
         .add_exit_system(GameState::Running, despawn_components::<Bird>)
@@ -128,13 +494,347 @@ fn main() {
         .add_exit_system(GameState::Running, despawn_components::<Bounds>)
         .add_exit_system(GameState::Running, despawn_components::<GameUi>)
         .add_exit_system(GameState::Running, despawn_components::<TilingBackground>)
+        .add_exit_system(GameState::Running, despawn_components::<Particle>)
         .add_exit_system(GameState::Running, remove_resource::<ObstacleConfig>)
-        .add_exit_system(GameState::Running, remove_resource::<Score>)
-        // Game Ended
-        .add_enter_system(GameState::Ended, immediately_restart_game)
+        // Game Over
+        // `Score` is kept alive through the Running -> GameOver transition so
+        // `setup_game_over_ui` can read the final tally; it's torn down on exit
+        // and `GameState::Running`'s enter systems create a fresh one next match.
+        .add_enter_system(GameState::GameOver, setup_game_over_ui)
+        .add_system(restart_on_space.run_in_state(GameState::GameOver))
+        .add_exit_system(GameState::GameOver, despawn_components::<GameOverUi>)
+        .add_exit_system(GameState::GameOver, remove_resource::<Score>)
         .run();
 }
 
+/// A fixed constant (`0xB12D_5EED`) compiled into both peers, not actually
+/// exchanged at session start, so both sides seed their `RollbackRng`
+/// identically before the first tick.
+#[derive(Resource, Clone, Copy)]
+struct NetplaySeed(u64);
+
+/// Gameplay events that should make noise. Gameplay systems only ever write
+/// these; `play_audio` is the single place that plays them (the looping music
+/// track is started once by `play_background_music`), so playback stays out of
+/// the simulation and rollback can suppress it cleanly.
+#[derive(Debug, Clone, Copy)]
+enum AudioEvent {
+    Flap,
+    Score,
+    Death,
+    Restart,
+}
+
+/// Set each rollback tick to whether that tick is a resimulation (replaying a
+/// past frame after a misprediction) rather than the newest, authoritative one.
+/// `play_audio` drains `AudioEvent`s either way but only plays sound when this
+/// is `false`, so resimulated ticks don't double up on flap/death/score sounds.
+#[derive(Resource, Default)]
+struct Resimulating(bool);
+
+fn detect_resimulation(
+    frame: Res<bevy_ggrs::RollbackFrameCount>,
+    session: Option<Res<bevy_ggrs::Session<BirdGgrsConfig>>>,
+    mut resimulating: ResMut<Resimulating>,
+) {
+    resimulating.0 = match session.as_deref() {
+        Some(bevy_ggrs::Session::P2PSession(session)) => frame.0 < session.confirmed_frame(),
+        _ => false,
+    };
+}
+
+fn play_audio(
+    mut audio_events: EventReader<AudioEvent>,
+    resimulating: Res<Resimulating>,
+    audio: Res<Audio>,
+    assets: Res<AssetServer>,
+) {
+    for event in audio_events.iter() {
+        if resimulating.0 {
+            continue;
+        }
+
+        let path = match event {
+            AudioEvent::Flap => "audio/flap.ogg",
+            AudioEvent::Score => "audio/score.ogg",
+            AudioEvent::Death => "audio/death.ogg",
+            AudioEvent::Restart => "audio/restart.ogg",
+        };
+        audio.play(assets.load(path));
+    }
+}
+
+fn play_background_music(audio: Res<Audio>, assets: Res<AssetServer>) {
+    audio
+        .play(assets.load("audio/bg_music.ogg"))
+        .looped();
+}
+
+/// Cosmetic juice events. Like `AudioEvent`, gameplay systems only ever write
+/// these so spawning stays an Update-stage concern the rollback schedule
+/// doesn't have to know about. A misprediction resimulates the tick that
+/// raised one of these, so `spawn_particles` checks `Resimulating` the same
+/// way `play_audio` does to avoid spawning a duplicate burst per resimulation.
+enum ParticleBurst {
+    Flap(Vec2),
+    Death(Vec2),
+}
+
+#[derive(Component)]
+struct Particle;
+
+#[derive(Component)]
+struct ParticleVelocity(Vec2);
+
+#[derive(Component)]
+struct Lifetime(Timer);
+
+const PARTICLE_GRAVITY: f32 = -600.0;
+
+fn spawn_particles(
+    mut commands: Commands,
+    mut particle_events: EventReader<ParticleBurst>,
+    resimulating: Res<Resimulating>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for event in particle_events.iter() {
+        if resimulating.0 {
+            continue;
+        }
+
+        let (origin, count, speed_range, lifetime_secs, size, color) = match event {
+            ParticleBurst::Flap(origin) => {
+                (*origin, 6, 80.0..160.0, 0.4, 6.0, Color::rgba(0.9, 0.9, 0.85, 1.0))
+            }
+            ParticleBurst::Death(origin) => {
+                (*origin, 16, 120.0..320.0, 0.8, 8.0, Color::rgba(0.8, 0.2, 0.2, 1.0))
+            }
+        };
+
+        for _ in 0..count {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let speed = rng.gen_range(speed_range.clone());
+            let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
+
+            commands
+                .spawn(SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::splat(size)),
+                        color,
+                        ..default()
+                    },
+                    transform: Transform::from_translation(origin.extend(3.0)),
+                    ..default()
+                })
+                .insert(Particle)
+                .insert(ParticleVelocity(velocity))
+                .insert(Lifetime(Timer::from_seconds(lifetime_secs, TimerMode::Once)))
+                .insert(Name::new("Particle"));
+        }
+    }
+}
+
+fn update_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particle_query: Query<(Entity, &mut Transform, &mut ParticleVelocity, &mut Lifetime, &mut Sprite), With<Particle>>,
+) {
+    for (entity, mut transform, mut velocity, mut lifetime, mut sprite) in particle_query.iter_mut() {
+        velocity.0.y += PARTICLE_GRAVITY * time.delta_seconds();
+        transform.translation += (velocity.0 * time.delta_seconds()).extend(0.0);
+
+        lifetime.0.tick(time.delta());
+        sprite.color.set_a(lifetime.0.percent_left());
+
+        if lifetime.0.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+trait AppGgrsRollbackExt {
+    /// Wires up the GGRS plugin: fixed-tick rollback schedule, input system, and
+    /// every component/resource that must be snapshot and restored on misprediction.
+    fn add_ggrs_rollback(&mut self) -> &mut Self;
+}
+
+impl AppGgrsRollbackExt for App {
+    fn add_ggrs_rollback(&mut self) -> &mut Self {
+        GGRSPlugin::<BirdGgrsConfig>::new()
+            .with_update_frequency(ROLLBACK_FPS)
+            .with_input_system(read_local_input)
+            .register_rollback_component::<Transform>()
+            .register_rollback_component::<Velocity>()
+            .register_rollback_component::<ExternalImpulse>()
+            .register_rollback_component::<Bird>()
+            .register_rollback_resource::<Score>()
+            .register_rollback_resource::<RollbackRng>()
+            .with_rollback_schedule(
+                Schedule::default().with_stage(
+                    "rollback_stage",
+                    SystemStage::parallel()
+                        .with_system(detect_resimulation)
+                        .with_system(jump_on_space_ggrs)
+                        .with_system(spawn_obstacles)
+                        .with_system(bird_rotates_with_velocity)
+                        .with_system(step_rapier_in_rollback)
+                        // These consume `CollisionEvent`/`ContactForceEvent` from the
+                        // Rapier step just above, and mutate rollback-registered state
+                        // (`Score`, `Bird.alive`) or despawn rollback-tracked obstacle
+                        // entities. They must resimulate alongside physics rather than
+                        // run once per real frame, or a misprediction would score/kill
+                        // a bird or despawn an obstacle once per resimulation.
+                        .with_system(increment_score.run_on_event::<CollisionEvent>())
+                        .with_system(kill_bird_on_collision.run_on_event::<ContactForceEvent>())
+                        .with_system(despawn_offscreen_obstacles)
+                        .with_system(end_on_bird_leaves_screen),
+                ),
+            )
+            .build(self);
+        self
+    }
+}
+
+/// Which GGRS player handle this process is, which UDP port it binds
+/// locally, and where to reach the other peer. Parsed from CLI args so the
+/// two instances of the binary can take complementary roles (one 0/1, the
+/// other 1/0) instead of both hardcoding "local player 0".
+struct NetplayArgs {
+    local_handle: usize,
+    local_port: u16,
+    remote_addr: SocketAddr,
+}
+
+impl NetplayArgs {
+    /// Parses `--local-handle <0|1> --local-port <port> --remote-addr <ip:port>`.
+    /// Panics with a descriptive message if an option is missing or malformed,
+    /// same as the other `.expect`-driven setup in `insert_ggrs_session`.
+    fn from_env_args() -> Self {
+        let args: Vec<String> = std::env::args().collect();
+        let mut local_handle = None;
+        let mut local_port = None;
+        let mut remote_addr = None;
+
+        let mut i = 1;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--local-handle" => {
+                    local_handle = args.get(i + 1).and_then(|s| s.parse::<usize>().ok());
+                    i += 2;
+                }
+                "--local-port" => {
+                    local_port = args.get(i + 1).and_then(|s| s.parse::<u16>().ok());
+                    i += 2;
+                }
+                "--remote-addr" => {
+                    remote_addr = args.get(i + 1).and_then(|s| s.parse::<SocketAddr>().ok());
+                    i += 2;
+                }
+                _ => i += 1,
+            }
+        }
+
+        Self {
+            local_handle: local_handle
+                .filter(|&h| h == 0 || h == 1)
+                .expect("missing or invalid --local-handle <0|1>"),
+            local_port: local_port.expect("missing or invalid --local-port <port>"),
+            remote_addr: remote_addr.expect("missing or invalid --remote-addr <ip:port>"),
+        }
+    }
+
+    fn remote_handle(&self) -> usize {
+        1 - self.local_handle
+    }
+}
+
+/// Whether this process is playing solo or in a 2-player netplay match.
+/// Solo is the default so the game stays playable with no CLI args; passing
+/// `--netplay` (plus `NetplayArgs`' flags) opts into the P2P mode instead.
+#[derive(Resource)]
+enum NetplayMode {
+    Solo,
+    Online(NetplayArgs),
+}
+
+impl NetplayMode {
+    /// Reads `--netplay` off the process args; when present, the rest of
+    /// `NetplayArgs` is required and this process joins a 2-player match,
+    /// otherwise it plays solo against no remote peer at all.
+    fn from_env_args() -> Self {
+        if std::env::args().any(|a| a == "--netplay") {
+            NetplayMode::Online(NetplayArgs::from_env_args())
+        } else {
+            NetplayMode::Solo
+        }
+    }
+
+    fn num_players(&self) -> usize {
+        match self {
+            NetplayMode::Solo => 1,
+            NetplayMode::Online(_) => 2,
+        }
+    }
+}
+
+/// Binds the local socket (and, in `NetplayMode::Online`, connects to the
+/// remote peer), and seeds `RollbackRng` identically either way. Solo play
+/// still runs through a (single-player) GGRS `P2PSession` so the same
+/// rollback schedule and deterministic sim drive both modes.
+fn insert_ggrs_session(mut commands: Commands) {
+    let mode = NetplayMode::from_env_args();
+    let seed: u64 = 0xB12D_5EED;
+
+    let (local_port, builder) = match &mode {
+        NetplayMode::Solo => {
+            let builder = SessionBuilder::<BirdGgrsConfig>::new()
+                .with_num_players(mode.num_players())
+                .add_player(PlayerType::Local, 0)
+                .expect("failed to add local player");
+            (0, builder)
+        }
+        NetplayMode::Online(netplay) => {
+            let builder = SessionBuilder::<BirdGgrsConfig>::new()
+                .with_num_players(mode.num_players())
+                .add_player(PlayerType::Local, netplay.local_handle)
+                .expect("failed to add local player")
+                .add_player(PlayerType::Remote(netplay.remote_addr), netplay.remote_handle())
+                .expect("failed to add remote player");
+            (netplay.local_port, builder)
+        }
+    };
+
+    let socket = UdpNonBlockingSocket::bind_to_port(local_port).expect("failed to bind GGRS socket");
+    let session = builder.start_p2p_session(socket).expect("failed to start GGRS session");
+
+    commands.insert_resource(bevy_ggrs::Session::P2PSession(session));
+    commands.insert_resource(NetplaySeed(seed));
+    commands.insert_resource(RollbackRng::from_seed(seed));
+    commands.insert_resource(mode);
+}
+
+/// Reads this peer's flap state and selected preset and packs them into the
+/// `BirdInput` bits GGRS sends to the other peer.
+fn read_local_input(
+    _handle: In<ggrs::PlayerHandle>,
+    keys: Res<Input<KeyCode>>,
+    selected_preset: Res<SelectedBirdPreset>,
+) -> BirdInput {
+    let mut buttons = 0u8;
+    if keys.pressed(KeyCode::Space) {
+        buttons |= INPUT_FLAP;
+    }
+    buttons |= selected_preset.0.to_bits() << INPUT_PRESET_SHIFT;
+    BirdInput { buttons }
+}
+
+/// Steps Rapier manually so it runs inside the rollback schedule and gets
+/// re-simulated on misprediction, instead of its own always-on stage.
+fn step_rapier_in_rollback(world: &mut World) {
+    world.run_schedule(bevy_rapier2d::plugin::systems::PhysicsSchedule);
+}
+
 fn is_paused(pause_state: Res<PauseState>) -> bool {
     pause_state.0
 }
@@ -153,23 +853,72 @@ fn add_resource<T: Resource + Default>(mut commands: Commands) {
     commands.insert_resource(T::default());
 }
 
-fn immediately_restart_game(mut commands: Commands) {
+fn start_game_on_space(keys: Res<Input<KeyCode>>, mut commands: Commands) {
+    if keys.just_pressed(KeyCode::Space) {
+        commands.insert_resource(NextState(GameState::Running));
+    }
+}
+
+fn restart_on_space(
+    keys: Res<Input<KeyCode>>,
+    mut commands: Commands,
+    mut audio_events: EventWriter<AudioEvent>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    audio_events.send(AudioEvent::Restart);
     commands.insert_resource(NextState(GameState::Running));
 }
 
 fn bird_rotates_with_velocity(mut bird_query: Query<(&Bird, &mut Transform, &Velocity)>) {
-    let (bird, mut transform, velocity) = bird_query.single_mut();
-    if !bird.alive {
-        return;
+    for (bird, mut transform, velocity) in bird_query.iter_mut() {
+        if !bird.alive {
+            continue;
+        }
+
+        let normalized_velocity = velocity.linvel.normalize();
+        let mut rotation = normalized_velocity.y.atan2(normalized_velocity.x);
+        if rotation.is_nan() {
+            rotation = 0.0;
+        }
+        rotation = rotation * PI / 180.0 * 5.0;
+        transform.rotation.z = rotation;
     }
+}
+
+/// Applies each bird's flap from this tick's `PlayerInputs`, keyed by its
+/// `PlayerHandle`, so flaps come from GGRS instead of the raw keyboard.
+fn jump_on_space_ggrs(
+    inputs: Res<PlayerInputs<BirdGgrsConfig>>,
+    config: Res<GameConfig>,
+    mut audio_events: EventWriter<AudioEvent>,
+    mut particle_events: EventWriter<ParticleBurst>,
+    mut bird_query: Query<(
+        &PlayerHandle,
+        &Transform,
+        &mut Velocity,
+        &mut ExternalImpulse,
+        &Bird,
+        &FlapImpulseMultiplier,
+    )>,
+) {
+    for (handle, transform, mut v, mut i, b, multiplier) in bird_query.iter_mut() {
+        if !b.alive {
+            continue;
+        }
+
+        let (input, _) = inputs[handle.0];
+        if input.buttons & INPUT_FLAP == 0 {
+            continue;
+        }
 
-    let normalized_velocity = velocity.linvel.normalize();
-    let mut rotation = normalized_velocity.y.atan2(normalized_velocity.x);
-    if rotation.is_nan() {
-        rotation = 0.0;
+        audio_events.send(AudioEvent::Flap);
+        particle_events.send(ParticleBurst::Flap(transform.translation.truncate()));
+        v.linvel.y = 0.0;
+        i.impulse = Vec2::Y * config.jump_impulse * multiplier.0;
     }
-    rotation = rotation * PI / 180.0 * 5.0;
-    transform.rotation.z = rotation;
 }
 
 fn spawn_obstacles(
@@ -177,6 +926,8 @@ fn spawn_obstacles(
     bird_query: Query<&Transform, (With<Bird>, Without<Camera2d>)>,
     obstacle_query: Query<(&Transform, &ObstacleBundle)>,
     obstacle_config: Res<ObstacleConfig>,
+    mut rng: ResMut<RollbackRng>,
+    mut rollback_ids: ResMut<RollbackIdProvider>,
     assets: Res<AssetServer>,
 ) {
     let max_obstacle_x: f32 = obstacle_query
@@ -186,8 +937,14 @@ fn spawn_obstacles(
         .unwrap_or(FloatOrd(f32::MIN))
         .0;
 
-    let bird = bird_query.single();
-    let bird_x = bird.translation.x;
+    // Both peers' birds share one obstacle field, so spawning is driven by
+    // whichever bird is furthest ahead.
+    let bird_x = bird_query
+        .iter()
+        .map(|t| FloatOrd(t.translation.x))
+        .max()
+        .unwrap_or(FloatOrd(0.0))
+        .0;
 
     let screen_edge = bird_x + SCREEN_WIDTH / 2.0;
     let spawn_at = screen_edge + 50.0;
@@ -197,7 +954,7 @@ fn spawn_obstacles(
     }
 
     let width = 82.0;
-    let y_mid = random::<f32>()
+    let y_mid = rng.0.gen::<f32>()
         * (obstacle_config.y_mid_range.end() - obstacle_config.y_mid_range.start())
         + obstacle_config.y_mid_range.start();
 
@@ -208,6 +965,7 @@ fn spawn_obstacles(
             ..default()
         })
         .insert(Name::new(format!("Obstacle @ {spawn_at}")))
+        .insert(Rollback::new(rollback_ids.next_id()))
         .add_children(|commands| {
             commands
                 .spawn(SpriteBundle {
@@ -220,7 +978,8 @@ fn spawn_obstacles(
                     transform: Transform::from_xyz(0.0, 0.0, 2.0),
                     ..default()
                 })
-                .insert(Name::new("Ring Over"));
+                .insert(Name::new("Ring Over"))
+                .insert(Rollback::new(rollback_ids.next_id()));
 
             commands
                 .spawn(SpriteBundle {
@@ -233,7 +992,8 @@ fn spawn_obstacles(
                     transform: Transform::from_xyz(0.0, 0.0, 0.9),
                     ..default()
                 })
-                .insert(Name::new("Ring Under"));
+                .insert(Name::new("Ring Under"))
+                .insert(Rollback::new(rollback_ids.next_id()));
 
             let collider_height = 15.0;
 
@@ -245,7 +1005,8 @@ fn spawn_obstacles(
                     0.0,
                 )))
                 .insert(Wall)
-                .insert(Name::new(format!("Up @ {spawn_at}")));
+                .insert(Name::new(format!("Up @ {spawn_at}")))
+                .insert(Rollback::new(rollback_ids.next_id()));
 
             commands
                 .spawn(Collider::cuboid(width / 5.0, collider_height))
@@ -255,14 +1016,16 @@ fn spawn_obstacles(
                     0.0,
                 )))
                 .insert(Wall)
-                .insert(Name::new(format!("Down @ {spawn_at}")));
+                .insert(Name::new(format!("Down @ {spawn_at}")))
+                .insert(Rollback::new(rollback_ids.next_id()));
 
             commands
                 .spawn(Collider::cuboid(10.0, obstacle_config.y_mid_offset))
                 .insert(TransformBundle::from(Transform::from_xyz(0.0, 0.0, 0.0)))
                 .insert(Sensor)
                 .insert(ActiveEvents::COLLISION_EVENTS)
-                .insert(Name::new(format!("Sensor @ {spawn_at}")));
+                .insert(Name::new(format!("Sensor @ {spawn_at}")))
+                .insert(Rollback::new(rollback_ids.next_id()));
         });
 }
 
@@ -313,7 +1076,7 @@ fn tiling_background(
 
 fn despawn_offscreen_obstacles(
     mut commands: Commands,
-    bird_query: Query<&Transform, (With<Bird>, Without<Camera2d>)>,
+    bird_query: Query<&Transform, (With<LocalPlayer>, Without<Camera2d>)>,
     obstacle_query: Query<(Entity, &Transform, &ObstacleBundle)>,
 ) {
     let bird = bird_query.single();
@@ -330,7 +1093,7 @@ fn despawn_offscreen_obstacles(
 
 fn bounds_follow_bird(
     mut bounds_query: Query<&mut Transform, (With<Bounds>, Without<Bird>)>,
-    bird_query: Query<&Transform, (With<Bird>, Without<Bounds>)>,
+    bird_query: Query<&Transform, (With<LocalPlayer>, Without<Bounds>)>,
 ) {
     let bird = bird_query.single();
     let bird_x = bird.translation.x;
@@ -373,39 +1136,72 @@ fn reset_camera(mut camera_query: Query<&mut Transform, With<Camera2d>>) {
 
 fn camera_follows_bird(
     mut camera_query: Query<&mut Transform, (With<Camera2d>, Without<Bird>)>,
-    bird_query: Query<&Transform, (With<Bird>, Without<Camera2d>)>,
+    bird_query: Query<&Transform, (With<LocalPlayer>, Without<Camera2d>)>,
 ) {
     let mut camera = camera_query.single_mut();
     let bird = bird_query.single();
     camera.translation.x = bird.translation.x;
 }
 
-fn setup_bird(mut commands: Commands, assets: Res<AssetServer>) {
-    commands
-        .spawn(RigidBody::Dynamic)
-        .insert(SpriteBundle {
-            sprite: Sprite {
-                anchor: Anchor::Center,
-                custom_size: Some(Vec2::new(100.0, 50.0)),
+/// Marks the bird entity controlled by this peer, as opposed to the one
+/// mirroring the remote peer's `PlayerHandle`. Used by camera/bounds-follow,
+/// which only ever track the local player.
+#[derive(Component)]
+struct LocalPlayer;
+
+/// Spawns one `Bird` per `PlayerHandle` (0 is always local), one for solo
+/// play or two for a `NetplayMode::Online` match, replacing the old
+/// single-bird `setup_bird`.
+fn setup_players(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut rollback_ids: ResMut<RollbackIdProvider>,
+    inputs: Res<PlayerInputs<BirdGgrsConfig>>,
+    mode: Res<NetplayMode>,
+) {
+    for handle in 0..mode.num_players() {
+        // Each peer's preset choice rides along in its `BirdInput` (see
+        // `read_local_input`), so both sides read the *same* synced value for
+        // a given handle here rather than re-deriving it from the purely
+        // local `SelectedBirdPreset` resource.
+        let (input, _) = inputs[handle];
+        let preset = input.preset();
+        let stats = preset.stats();
+
+        let mut entity = commands.spawn(RigidBody::Dynamic);
+        entity
+            .insert(SpriteBundle {
+                sprite: Sprite {
+                    anchor: Anchor::Center,
+                    custom_size: Some(Vec2::new(100.0, 50.0)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, 0.0, 1.0),
+                texture: assets.load(stats.texture),
                 ..default()
-            },
-            transform: Transform::from_xyz(0.0, 0.0, 1.0),
-            texture: assets.load("images/player.png"),
-            ..default()
-        })
-        .insert(Collider::ball(25.0))
-        .insert(Sleeping::disabled())
-        .insert(Name::new("Bird"))
-        .insert(Velocity::zero())
-        .insert(ExternalImpulse::default())
-        .insert(Restitution::coefficient(2.0))
-        .insert(ActiveEvents::CONTACT_FORCE_EVENTS)
-        .insert(ExternalForce {
-            force: Vec2::X * 5.0,
-            ..default()
-        })
-        .insert(CollisionGroups::new(Group::ALL, Group::ALL))
-        .insert(Bird { alive: true });
+            })
+            .insert(Collider::ball(stats.collider_radius))
+            .insert(GravityScale(stats.gravity_scale))
+            .insert(Sleeping::disabled())
+            .insert(Name::new(format!("Bird {handle} ({})", preset.name())))
+            .insert(Velocity::zero())
+            .insert(ExternalImpulse::default())
+            .insert(Restitution::coefficient(stats.restitution))
+            .insert(ActiveEvents::CONTACT_FORCE_EVENTS)
+            .insert(ExternalForce {
+                force: Vec2::X * 5.0,
+                ..default()
+            })
+            .insert(CollisionGroups::new(Group::ALL, Group::ALL))
+            .insert(Bird { alive: true })
+            .insert(FlapImpulseMultiplier(stats.flap_impulse_multiplier))
+            .insert(PlayerHandle(handle))
+            .insert(Rollback::new(rollback_ids.next_id()));
+
+        if handle == 0 {
+            entity.insert(LocalPlayer);
+        }
+    }
 }
 
 fn toggle_pause(
@@ -423,49 +1219,114 @@ fn toggle_pause(
     }
 }
 
-fn jump_on_space(
-    keys: Res<Input<KeyCode>>,
-    mut bird_query: Query<(&mut Velocity, &mut ExternalImpulse, &Bird)>,
-) {
-    if !keys.just_pressed(KeyCode::Space) {
-        return;
+fn toggle_fps_display(keys: Res<Input<KeyCode>>, mut show_fps: ResMut<ShowFps>) {
+    if keys.just_pressed(KeyCode::F3) {
+        show_fps.0 = !show_fps.0;
     }
+}
 
-    let (mut v, mut i, b) = bird_query.single_mut();
-    if !b.alive {
+fn update_fps_text(
+    show_fps: Res<ShowFps>,
+    diagnostics: Res<Diagnostics>,
+    mut fps_text_query: Query<&mut Text, With<FpsText>>,
+) {
+    let mut fps_text = fps_text_query.single_mut();
+
+    if !show_fps.0 {
+        fps_text.sections[0].value = String::new();
         return;
     }
 
-    v.linvel.y = 0.0;
-    i.impulse = Vec2::Y * 50.0;
+    let fps = diagnostics
+        .get(FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+        .unwrap_or(0.0);
+    let frame_time_ms = diagnostics
+        .get(FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.smoothed())
+        .unwrap_or(0.0)
+        * 1000.0;
+
+    fps_text.sections[0].value = format!("{fps:.0} fps ({frame_time_ms:.1} ms)");
+}
+
+/// Drops to a low-power reactive `WinitSettings` update mode while paused or
+/// outside `GameState::Running` (menu, game over), and back to continuous
+/// while actively playing, to keep idle CPU/GPU usage down on laptops.
+fn adaptive_power_mode(
+    game_state: Res<CurrentState<GameState>>,
+    pause_state: Res<PauseState>,
+    mode: Res<NetplayMode>,
+    mut winit_settings: ResMut<WinitSettings>,
+) {
+    // An online match's GGRS session needs the app loop ticking steadily to poll
+    // its UDP socket and keep the peer connection alive, including while players
+    // are sitting on the menu/game-over screen waiting for/after a match, so it's
+    // exempted from the reactive switch the same as active, unpaused play. Solo
+    // play's single-player session has no peer to keep alive and is not exempted.
+    let online = matches!(*mode, NetplayMode::Online(_));
+    let active = online || (game_state.0 == GameState::Running && !pause_state.0);
+    *winit_settings = if active {
+        WinitSettings::game()
+    } else {
+        WinitSettings::desktop_app()
+    };
 }
 
-fn increment_score(mut collision_events: EventReader<CollisionEvent>, mut score: ResMut<Score>) {
+fn increment_score(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut audio_events: EventWriter<AudioEvent>,
+    mut score: ResMut<Score>,
+) {
     for collision_event in collision_events.iter() {
         let CollisionEvent::Started(_, _, _) = collision_event else {
             continue
         };
 
         score.0 += 1;
+        audio_events.send(AudioEvent::Score);
     }
 }
 
-fn kill_bird_on_collision(mut bird_query: Query<(&mut CollisionGroups, &mut Bird)>) {
-    let (mut bird_collision_groups, mut bird) = bird_query.single_mut();
-
-    // Bird no longer collides with anything
-    bird_collision_groups.memberships = Group::NONE;
-    bird_collision_groups.filters = Group::NONE;
-    bird.alive = false;
+fn kill_bird_on_collision(
+    mut contact_events: EventReader<ContactForceEvent>,
+    mut bird_query: Query<(&mut CollisionGroups, &mut Bird, &Transform)>,
+    mut audio_events: EventWriter<AudioEvent>,
+    mut particle_events: EventWriter<ParticleBurst>,
+) {
+    // Only the bird(s) actually involved in a contact die.
+    for event in contact_events.iter() {
+        for entity in [event.collider1, event.collider2] {
+            let Ok((mut bird_collision_groups, mut bird, transform)) = bird_query.get_mut(entity) else {
+                continue;
+            };
+            if !bird.alive {
+                continue;
+            }
+
+            audio_events.send(AudioEvent::Death);
+            particle_events.send(ParticleBurst::Death(transform.translation.truncate()));
+
+            // Bird no longer collides with anything
+            bird_collision_groups.memberships = Group::NONE;
+            bird_collision_groups.filters = Group::NONE;
+            bird.alive = false;
+        }
+    }
 }
 
+/// Ends the match once every bird has left the screen — in the 2-player
+/// netplay mode that's "last one alive wins" rather than a single death.
 fn end_on_bird_leaves_screen(bird_query: Query<&Transform, With<Bird>>, mut commands: Commands) {
-    let bird = bird_query.single();
-    let bird_y = bird.translation.y;
     let margin = 50.0;
-    if bird_y < -1.0 * SCREEN_HEIGHT / 2.0 - margin || bird_y > SCREEN_HEIGHT / 2.0 + margin {
-        println!("Bird dead");
-        commands.insert_resource(NextState(GameState::Ended));
+    let all_gone = bird_query.iter().all(|transform| {
+        transform.translation.y < -1.0 * SCREEN_HEIGHT / 2.0 - margin
+            || transform.translation.y > SCREEN_HEIGHT / 2.0 + margin
+    });
+
+    if all_gone {
+        println!("Match over");
+        commands.insert_resource(NextState(GameState::GameOver));
     }
 }
 
@@ -505,6 +1366,147 @@ fn setup_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                 )
                 .insert(Name::new("Score Text"))
                 .insert(ScoreText);
+
+            commands
+                .spawn(
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                            font_size: 20.0,
+                            color: Color::BLACK,
+                        },
+                    )
+                    .with_text_alignment(TextAlignment::TOP_RIGHT)
+                    .with_style(Style {
+                        position_type: PositionType::Absolute,
+                        position: UiRect {
+                            top: Val::Px(10.0),
+                            right: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    }),
+                )
+                .insert(Name::new("FPS Text"))
+                .insert(FpsText);
+        });
+}
+
+fn setup_main_menu_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    preset: Res<SelectedBirdPreset>,
+) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            ..default()
+        })
+        .insert(MainMenuUi)
+        .insert(Name::new("Main Menu UI"))
+        .add_children(|commands| {
+            commands
+                .spawn(TextBundle::from_section(
+                    "Bevy Bird",
+                    TextStyle {
+                        font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                        font_size: 60.0,
+                        color: Color::BLACK,
+                    },
+                ))
+                .insert(Name::new("Title Text"));
+
+            commands
+                .spawn(TextBundle::from_section(
+                    "Press Space to start",
+                    TextStyle {
+                        font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                        font_size: 30.0,
+                        color: Color::BLACK,
+                    },
+                ))
+                .insert(Name::new("Prompt Text"));
+
+            commands
+                .spawn(TextBundle::from_section(
+                    format!("Bird: {} (Tab to cycle)", preset.0.name()),
+                    TextStyle {
+                        font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                        font_size: 20.0,
+                        color: Color::BLACK,
+                    },
+                ))
+                .insert(PresetText)
+                .insert(Name::new("Preset Text"));
+        });
+}
+
+fn setup_game_over_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    score: Res<Score>,
+    mut high_score: ResMut<HighScore>,
+) {
+    if score.0 > high_score.0 {
+        high_score.0 = score.0;
+        save_high_score(&high_score);
+    }
+
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            ..default()
+        })
+        .insert(GameOverUi)
+        .insert(Name::new("Game Over UI"))
+        .add_children(|commands| {
+            commands
+                .spawn(TextBundle::from_section(
+                    format!("Score: {}", score.0),
+                    TextStyle {
+                        font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                        font_size: 40.0,
+                        color: Color::BLACK,
+                    },
+                ))
+                .insert(Name::new("Final Score Text"));
+
+            commands
+                .spawn(TextBundle::from_section(
+                    format!("High score: {}", high_score.0),
+                    TextStyle {
+                        font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                        font_size: 30.0,
+                        color: Color::BLACK,
+                    },
+                ))
+                .insert(Name::new("High Score Text"));
+
+            commands
+                .spawn(TextBundle::from_section(
+                    "Press Space to play again",
+                    TextStyle {
+                        font: asset_server.load("fonts/OpenSans-Regular.ttf"),
+                        font_size: 20.0,
+                        color: Color::BLACK,
+                    },
+                ))
+                .insert(Name::new("Prompt Text"));
         });
 }
 
@@ -518,17 +1520,42 @@ fn update_score_text(mut score_text_query: Query<&mut Text, With<ScoreText>>, sc
     score_text.sections[0].value = format!("Score: {score}");
 }
 
-fn score_increases_difficulty(mut obstacle_config: ResMut<ObstacleConfig>, score: Res<Score>) {
+fn cycle_bird_preset(keys: Res<Input<KeyCode>>, mut preset: ResMut<SelectedBirdPreset>) {
+    if keys.just_pressed(KeyCode::Tab) {
+        preset.0 = preset.0.cycle_next();
+    }
+}
+
+fn update_preset_text(
+    mut preset_text_query: Query<&mut Text, With<PresetText>>,
+    preset: Res<SelectedBirdPreset>,
+) {
+    if !preset.is_changed() {
+        return;
+    }
+
+    let mut preset_text = preset_text_query.single_mut();
+    preset_text.sections[0].value = format!("Bird: {} (Tab to cycle)", preset.0.name());
+}
+
+fn score_increases_difficulty(
+    mut obstacle_config: ResMut<ObstacleConfig>,
+    score: Res<Score>,
+    config: Res<GameConfig>,
+) {
     if !score.is_changed() {
         return;
     }
 
     let score = score.0 as f32;
-    let default_obstacle_config = ObstacleConfig::default();
-
-    obstacle_config.min_x_between = 500.0_f32.max(default_obstacle_config.min_x_between - score);
-    obstacle_config.y_mid_offset = 100.0_f32.max(default_obstacle_config.y_mid_offset - score);
-    obstacle_config.y_mid_range = (-200.0_f32
+    let default_obstacle_config = ObstacleConfig::from(config.as_ref());
+    let difficulty = config.difficulty;
+
+    obstacle_config.min_x_between =
+        difficulty.min_x_between_floor.max(default_obstacle_config.min_x_between - score);
+    obstacle_config.y_mid_offset =
+        difficulty.y_mid_offset_floor.max(default_obstacle_config.y_mid_offset - score);
+    obstacle_config.y_mid_range = (-difficulty.y_mid_range_clamp
         .max(default_obstacle_config.y_mid_range.start() - score))
-        ..=(200.0_f32.min(default_obstacle_config.y_mid_range.end() + score));
+        ..=(difficulty.y_mid_range_clamp.min(default_obstacle_config.y_mid_range.end() + score));
 }